@@ -1,7 +1,8 @@
-//! A library for analyzing PE (Portable Executable) file dependencies, similar to the `ldd` tool on Linux.
+//! A library for analyzing the dynamic dependencies of PE, ELF and Mach-O files,
+//! similar to the `ldd` tool on Linux.
 //!
 //! This library provides functionality to:
-//! - Parse PE files and extract their dynamic dependencies
+//! - Parse PE, ELF and Mach-O files and extract their dynamic dependencies
 //! - Search for dependencies in specified directories
 //! - Report missing dependencies
 //!
@@ -10,10 +11,13 @@
 //! Basic usage:
 //!
 //! ```no_run
-//! use wldd_rs::{Config, run};
+//! use wldd_rs::{Config, OutputFormat, run};
 //!
 //! let config = Config {
-//!     dir: vec!["C:\\Windows\\System32".into()],
+//!     dirs: vec!["C:\\Windows\\System32".into()],
+//!     recursive: false,
+//!     no_default_paths: false,
+//!     format: OutputFormat::Text,
 //!     files: vec!["my_program.exe".into()],
 //! };
 //!
@@ -21,14 +25,16 @@
 //! ```
 
 use std::{
+    collections::{HashSet, VecDeque},
     fs,
     io,
     path::{Path, PathBuf},
 };
 
-use clap::Parser;
-use goblin::pe;
+use clap::{Parser, ValueEnum};
+use goblin::{mach, Object};
 use memmap2::Mmap;
+use serde::Serialize;
 use thiserror::Error;
 
 /// Configuration options for the dependency analyzer
@@ -39,11 +45,37 @@ pub struct Config {
     #[arg(short, long, value_name = "DIRECTORY")]
     pub dirs: Vec<PathBuf>,
 
+    /// Recursively resolve the full transitive dependency tree (shown nested
+    /// with `--format tree` or `json`; `text` lists only direct dependencies)
+    #[arg(short, long)]
+    pub recursive: bool,
+
+    /// Don't search the default paths (application directory, System32, the
+    /// Windows directory, the current directory, `/usr/lib`, `/lib`,
+    /// LD_LIBRARY_PATH, DYLD_LIBRARY_PATH and PATH)
+    #[arg(long)]
+    pub no_default_paths: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    pub format: OutputFormat,
+
     /// Files to analyze (at least one required)
     #[arg(required = true)]
     pub files: Vec<PathBuf>,
 }
 
+/// Output format for reported dependencies
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable, `ldd`-style lines (the default)
+    Text,
+    /// Human-readable indented tree, nesting transitive dependencies under their parent
+    Tree,
+    /// Machine-readable JSON, one object per analyzed file
+    Json,
+}
+
 /// Error type for all operations in this crate
 #[derive(Error, Debug)]
 pub enum WlddError {
@@ -59,12 +91,104 @@ pub enum WlddError {
     #[error("Invalid search directory: {0}")]
     InvalidDirectory(String),
 
-    /// Errors occurring during PE file parsing
-    #[error("PE parse error: {0}")]
-    PeParseError(String),
+    /// Errors occurring while parsing an object file
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
+    /// Errors occurring while serializing a report
+    #[error("Serialization error: {0}")]
+    SerializationError(#[from] serde_json::Error),
 }
 
-/// Main entry point for analyzing PE file dependencies
+/// The object file format a dependency analysis was performed on
+///
+/// Each format has its own convention for dynamic library names and its own
+/// way of expressing additional search directories, so the format is carried
+/// alongside the resolved dependencies rather than guessed from the name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectFormat {
+    /// Windows Portable Executable
+    Pe,
+    /// Linux/Unix Executable and Linkable Format
+    Elf,
+    /// macOS/iOS Mach-O
+    MachO,
+}
+
+impl ObjectFormat {
+    /// Expands a library name into the filenames it could plausibly be found under
+    ///
+    /// PE imports already carry their extension (e.g. `foo.dll`), so they're
+    /// returned unchanged. ELF and Mach-O imports are sometimes bare names
+    /// (as produced by `-l<name>` at link time): ELF tries the shared and
+    /// static library naming conventions, Mach-O only the shared `.dylib` ones.
+    ///
+    /// # Arguments
+    /// * `name` - Library name as it appeared in the dependent file
+    ///
+    /// # Returns
+    /// Candidate filenames to search for, in the order they should be tried
+    fn library_candidates(self, name: &str) -> Vec<String> {
+        match self {
+            ObjectFormat::Pe => vec![name.to_string()],
+            ObjectFormat::Elf => {
+                if name.starts_with("lib") || name.contains(".so") {
+                    vec![name.to_string()]
+                } else {
+                    vec![format!("lib{name}.so"), format!("{name}.so"), format!("lib{name}.a")]
+                }
+            }
+            ObjectFormat::MachO => {
+                if name.starts_with("lib") || name.ends_with(".dylib") {
+                    vec![name.to_string()]
+                } else {
+                    vec![format!("lib{name}.dylib"), format!("{name}.dylib")]
+                }
+            }
+        }
+    }
+}
+
+/// The dependencies of an analyzed object file, together with the format they
+/// were extracted from and any additional search directories the format
+/// itself specifies (e.g. ELF `DT_RPATH`/`DT_RUNPATH`)
+struct ParsedObject {
+    format: ObjectFormat,
+    libraries: Vec<String>,
+    extra_search_dirs: Vec<PathBuf>,
+}
+
+/// A single resolved dependency, and (when `--recursive` is given) its own
+/// dependencies nested underneath it
+#[derive(Serialize)]
+pub struct DependencyRecord {
+    /// Library name as it appeared in the importing file
+    pub name: String,
+    /// Full path where the dependency was located, or `null` if it wasn't found
+    pub resolved_path: Option<PathBuf>,
+    /// Directories that were searched to resolve `name`
+    pub searched_dirs: Vec<PathBuf>,
+    /// Set when this library already appeared earlier in the tree, so its own
+    /// dependencies weren't expanded again
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub already_shown: bool,
+    /// This dependency's own dependencies, populated only in recursive mode
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<DependencyRecord>,
+}
+
+/// The report for a single analyzed file, in the shape that drives every output format
+#[derive(Serialize)]
+pub struct FileReport {
+    /// Path to the analyzed file
+    pub path: PathBuf,
+    /// Whether the file is a dynamic executable (has any dependencies at all)
+    pub is_dynamic: bool,
+    /// The file's dependencies, direct or transitive
+    pub dependencies: Vec<DependencyRecord>,
+}
+
+/// Main entry point for analyzing object file dependencies
 ///
 /// # Arguments
 /// * `config` - Configuration specifying files to analyze and search directories
@@ -75,8 +199,14 @@ pub enum WlddError {
 ///
 /// # Examples
 /// ```
-/// # use wldd_rs::{Config, run};
-/// # let config = Config { dir: vec![], files: vec![] };
+/// # use wldd_rs::{Config, OutputFormat, run};
+/// # let config = Config {
+/// #     dirs: vec![],
+/// #     recursive: false,
+/// #     no_default_paths: false,
+/// #     format: OutputFormat::Text,
+/// #     files: vec![],
+/// # };
 /// if let Err(e) = run(config) {
 ///     eprintln!("Error: {}", e);
 /// }
@@ -89,14 +219,26 @@ pub fn run(config: Config) -> Result<(), WlddError> {
     for file in &config.files {
         validate_file(file)?;
 
-        let deps = get_pe_dependencies(file)?;
-        if deps.is_empty() {
-            eprintln!("{}: not a dynamic executable", file.display());
-            continue;
+        let parsed = get_dependencies(file)?;
+
+        let mut search_dirs = config.dirs.clone();
+        search_dirs.extend(parsed.extra_search_dirs.clone());
+        if !config.no_default_paths {
+            search_dirs.extend(default_search_paths(file));
         }
 
-        println!("{}:", file.display());
-        check_dependencies(&deps, &config.dirs);
+        let dependencies = if parsed.libraries.is_empty() {
+            Vec::new()
+        } else {
+            build_dependency_tree(parsed.format, &parsed.libraries, &search_dirs, config.recursive)
+        };
+
+        let report = FileReport {
+            path: file.clone(),
+            is_dynamic: !parsed.libraries.is_empty(),
+            dependencies,
+        };
+        render_report(&report, config.format)?;
     }
 
     Ok(())
@@ -120,6 +262,48 @@ fn validate_file(file: &Path) -> Result<(), WlddError> {
     }
 }
 
+/// Builds the default dependency search path for `file`: the Windows loader's
+/// search order followed by Unix library locations, the same list on every host
+///
+/// # Arguments
+/// * `file` - The file being analyzed; its parent directory is searched first
+///
+/// # Returns
+/// An ordered list of directories: the directory containing `file`, the
+/// Windows system directory (`%SystemRoot%\System32`) and Windows directory
+/// (`%SystemRoot%`) when set, the current working directory, the
+/// Unix-conventional library roots (`/usr/lib`, `/lib`), and finally every
+/// directory listed in `LD_LIBRARY_PATH`, `DYLD_LIBRARY_PATH` and `PATH`
+/// (split using the platform's own separator, `;` on Windows and `:`
+/// elsewhere)
+fn default_search_paths(file: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(parent) = file.parent() {
+        paths.push(parent.to_path_buf());
+    }
+
+    if let Ok(system_root) = std::env::var("SystemRoot") {
+        paths.push(PathBuf::from(&system_root).join("System32"));
+        paths.push(PathBuf::from(system_root));
+    }
+
+    if let Ok(cwd) = std::env::current_dir() {
+        paths.push(cwd);
+    }
+
+    paths.push(PathBuf::from("/usr/lib"));
+    paths.push(PathBuf::from("/lib"));
+
+    for var in ["LD_LIBRARY_PATH", "DYLD_LIBRARY_PATH", "PATH"] {
+        if let Ok(value) = std::env::var(var) {
+            paths.extend(std::env::split_paths(&value));
+        }
+    }
+
+    paths
+}
+
 /// Validates that a path exists and is a directory
 ///
 /// # Arguments
@@ -136,63 +320,250 @@ fn validate_dir(dir: &Path) -> Result<(), WlddError> {
     }
 }
 
-/// Extracts dependencies from a PE file
+/// Extracts dependencies from a PE, ELF or Mach-O file
 ///
 /// # Arguments
-/// * `file_path` - Path to the PE file to analyze
+/// * `file_path` - Path to the object file to analyze
 ///
 /// # Returns
-/// * `Ok(Vec<String>)` - List of dependency filenames
-/// * `Err(WlddError)` - If the file couldn't be read or parsed
+/// * `Ok(ParsedObject)` - The detected format, its dependency names, and any
+///   additional search directories the format itself specifies
+/// * `Err(WlddError)` - If the file couldn't be read or parsed, or isn't a
+///   format this crate understands
 ///
 /// # Notes
 /// This function uses memory mapping for efficient file access
-fn get_pe_dependencies(file_path: &Path) -> Result<Vec<String>, WlddError> {
+fn get_dependencies(file_path: &Path) -> Result<ParsedObject, WlddError> {
     let file = fs::File::open(file_path)?;
     let mmap = unsafe { Mmap::map(&file)? };
-    let pe = pe::PE::parse(&mmap).map_err(|e| WlddError::PeParseError(e.to_string()))?;
 
-    let mut deps = Vec::new();
+    match Object::parse(&mmap).map_err(|e| WlddError::ParseError(e.to_string()))? {
+        Object::PE(pe) => Ok(ParsedObject {
+            format: ObjectFormat::Pe,
+            libraries: pe.libraries.iter().map(|lib| lib.to_string()).collect(),
+            extra_search_dirs: Vec::new(),
+        }),
+        Object::Elf(elf) => {
+            let origin = file_path.parent().unwrap_or_else(|| Path::new("."));
+            let extra_search_dirs = elf
+                .rpaths
+                .iter()
+                .chain(elf.runpaths.iter())
+                .map(|path| PathBuf::from(path.replace("$ORIGIN", &origin.display().to_string())))
+                .collect();
+
+            Ok(ParsedObject {
+                format: ObjectFormat::Elf,
+                libraries: elf.libraries.iter().map(|lib| lib.to_string()).collect(),
+                extra_search_dirs,
+            })
+        }
+        Object::Mach(mach::Mach::Binary(macho)) => Ok(ParsedObject {
+            format: ObjectFormat::MachO,
+            // `libs[0]` is a goblin-inserted "self" sentinel for the binary itself, not a dependency
+            libraries: macho.libs.iter().skip(1).map(|lib| lib.to_string()).collect(),
+            extra_search_dirs: Vec::new(),
+        }),
+        Object::Mach(mach::Mach::Fat(fat)) => {
+            let libraries = fat
+                .into_iter()
+                .filter_map(|arch| arch.ok())
+                .find_map(|arch| match arch {
+                    mach::SingleArch::MachO(macho) => {
+                        Some(macho.libs.iter().skip(1).map(|lib| lib.to_string()).collect())
+                    }
+                    mach::SingleArch::Archive(_) => None,
+                })
+                .unwrap_or_default();
 
-    for import in pe.libraries.iter() {
-        deps.push(import.to_string());
+            Ok(ParsedObject { format: ObjectFormat::MachO, libraries, extra_search_dirs: Vec::new() })
+        }
+        _ => Err(WlddError::ParseError("unsupported object format".to_string())),
     }
+}
 
-    Ok(deps)
+/// Searches `dirs` in order for a file matching `dep` under `format`'s naming conventions
+///
+/// # Arguments
+/// * `format` - The object format `dep` was imported by, used to expand bare
+///   library names into candidate filenames
+/// * `dep` - Dependency name to search for
+/// * `dirs` - Directories to search in
+///
+/// # Returns
+/// * `Some(PathBuf)` - The first directory/candidate combination that exists
+/// * `None` - If `dep` wasn't found in any search directory
+fn find_dependency(format: ObjectFormat, dep: &str, dirs: &[PathBuf]) -> Option<PathBuf> {
+    let candidates = format.library_candidates(dep);
+    dirs.iter()
+        .flat_map(|dir| candidates.iter().map(move |candidate| dir.join(candidate)))
+        .find(|path| path.is_file())
 }
 
-/// Checks where dependencies are found in the search paths
+/// Resolves `deps` and, in recursive mode, their full transitive closure
+///
+/// Walks a worklist/BFS queue of (library name, format, parent index) items.
+/// Names are tracked case-insensitively in a visited set so that cycles in
+/// the dependency graph (A -> B -> A) only expand a library's own
+/// dependencies once; every occurrence still has its own path resolved and is
+/// recorded, with repeats marked via `already_shown` so callers can tell a
+/// duplicate-but-resolved dependency apart from a genuinely missing one. Each
+/// dependency is re-parsed with its own detected format, since nothing
+/// guarantees it's the same object format as the file that imports it.
 ///
 /// # Arguments
-/// * `deps` - List of dependency filenames to search for
+/// * `format` - The object format `deps` was imported by
+/// * `deps` - Direct dependencies of the file being analyzed
 /// * `dirs` - Directories to search in
+/// * `recursive` - Whether to resolve and nest each dependency's own dependencies
 ///
-/// # Output
-/// Prints to stdout for each dependency:
-/// - The path where it was found (if any)
-/// - "Not found" if the dependency wasn't found in any search directory
-fn check_dependencies(deps: &[String], dirs: &[PathBuf]) {
-    let max_len = deps.iter().map(|d| d.len()).max().unwrap_or(0);
-
-    for dep in deps {
-        let mut found = false;
-        for dir in dirs {
-            let dep_path = dir.join(dep);
-            if dep_path.is_file() {
-                if !found {
-                    println!("\t{:width$} => {}", dep, dir.display(), width = max_len);
-                    found = true;
-                } else {
-                    println!("\t{:width$} => {}", "", dir.display(), width = max_len);
-                }
+/// # Returns
+/// The top-level [`DependencyRecord`]s, with `children` populated only when `recursive` is set
+fn build_dependency_tree(
+    format: ObjectFormat,
+    deps: &[String],
+    dirs: &[PathBuf],
+    recursive: bool,
+) -> Vec<DependencyRecord> {
+    let mut visited = HashSet::new();
+    let mut nodes = Vec::new();
+    let mut parents: Vec<Option<usize>> = Vec::new();
+    let mut worklist: VecDeque<(String, ObjectFormat, Option<usize>)> =
+        deps.iter().map(|dep| (dep.clone(), format, None)).collect();
+
+    while let Some((name, format, parent)) = worklist.pop_front() {
+        let resolved_path = find_dependency(format, &name, dirs);
+        let already_shown = recursive && !visited.insert(name.to_lowercase());
+        let index = nodes.len();
+
+        if recursive && !already_shown {
+            if let Some(child) = resolved_path.as_deref().and_then(|path| get_dependencies(path).ok()) {
+                worklist.extend(
+                    child.libraries.into_iter().map(|dep| (dep, child.format, Some(index))),
+                );
             }
         }
-        if !found {
-            println!("\t{:width$} => Not found", dep, width = max_len);
+
+        nodes.push(DependencyRecord {
+            name,
+            resolved_path,
+            searched_dirs: dirs.to_vec(),
+            already_shown,
+            children: Vec::new(),
+        });
+        parents.push(parent);
+    }
+
+    nest_dependency_tree(nodes, &parents)
+}
+
+/// Reassembles the flat, parent-indexed output of [`build_dependency_tree`]'s
+/// BFS into a nested tree, moving each node into its parent's `children`
+///
+/// # Arguments
+/// * `nodes` - Flat records in the order they were discovered
+/// * `parents` - `parents[i]` is the index of `nodes[i]`'s parent, or `None` at the root
+///
+/// # Returns
+/// The top-level records, each with its descendants nested underneath
+fn nest_dependency_tree(
+    nodes: Vec<DependencyRecord>,
+    parents: &[Option<usize>],
+) -> Vec<DependencyRecord> {
+    let mut children_of: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (index, parent) in parents.iter().enumerate() {
+        if let Some(parent_index) = parent {
+            children_of[*parent_index].push(index);
+        }
+    }
+
+    let mut slots: Vec<Option<DependencyRecord>> = nodes.into_iter().map(Some).collect();
+
+    fn assemble(
+        index: usize,
+        slots: &mut [Option<DependencyRecord>],
+        children_of: &[Vec<usize>],
+    ) -> DependencyRecord {
+        let mut node = slots[index].take().expect("each node is assembled exactly once");
+        node.children = children_of[index].iter().map(|&child| assemble(child, slots, children_of)).collect();
+        node
+    }
+
+    parents
+        .iter()
+        .enumerate()
+        .filter(|(_, parent)| parent.is_none())
+        .map(|(index, _)| assemble(index, &mut slots, &children_of))
+        .collect()
+}
+
+/// Renders a [`FileReport`] in the requested [`OutputFormat`]
+///
+/// # Arguments
+/// * `report` - The analyzed file and its resolved dependencies
+/// * `format` - Which output format to render
+///
+/// # Returns
+/// * `Err(WlddError::SerializationError)` if JSON serialization fails
+fn render_report(report: &FileReport, format: OutputFormat) -> Result<(), WlddError> {
+    if !report.is_dynamic && format != OutputFormat::Json {
+        eprintln!("{}: not a dynamic executable", report.path.display());
+        return Ok(());
+    }
+
+    match format {
+        OutputFormat::Text => {
+            println!("{}:", report.path.display());
+            let max_len = report.dependencies.iter().map(|d| d.name.len()).max().unwrap_or(0);
+            for dep in &report.dependencies {
+                print_dependency_line(dep, max_len);
+            }
+        }
+        OutputFormat::Tree => {
+            println!("{}:", report.path.display());
+            for dep in &report.dependencies {
+                print_dependency_tree(dep, 1);
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(report)?),
+    }
+
+    Ok(())
+}
+
+/// Prints a single dependency as an `ldd`-style aligned line
+///
+/// Only the top-level `resolved_path`/`already_shown` state is printed;
+/// nested `children` are ignored, since flat text output has no notion of depth.
+fn print_dependency_line(dep: &DependencyRecord, max_len: usize) {
+    if dep.already_shown {
+        println!("\t{:width$} (already shown)", dep.name, width = max_len);
+    } else {
+        match &dep.resolved_path {
+            Some(path) => println!("\t{:width$} => {}", dep.name, path.display(), width = max_len),
+            None => println!("\t{:width$} => Not found", dep.name, width = max_len),
         }
     }
 }
 
+/// Recursively prints a dependency and its children, indented by depth
+fn print_dependency_tree(dep: &DependencyRecord, depth: usize) {
+    let prefix = "\t".repeat(depth);
+    if dep.already_shown {
+        println!("{prefix}{} (already shown)", dep.name);
+        return;
+    }
+
+    match &dep.resolved_path {
+        Some(path) => println!("{prefix}{} => {}", dep.name, path.display()),
+        None => println!("{prefix}{} => Not found", dep.name),
+    }
+
+    for child in &dep.children {
+        print_dependency_tree(child, depth + 1);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -211,4 +582,87 @@ mod tests {
         let result = validate_file(Path::new("/nonexistent/file"));
         assert!(result.is_err());
     }
+
+    /// Tests that a library name revisited in the same tree is marked as
+    /// already shown instead of being expanded again, case-insensitively
+    #[test]
+    fn test_build_dependency_tree_breaks_cycles() {
+        let deps = vec!["A.DLL".to_string(), "a.dll".to_string()];
+        let tree = build_dependency_tree(ObjectFormat::Pe, &deps, &[], true);
+
+        assert!(!tree[0].already_shown);
+        assert!(tree[1].already_shown);
+    }
+
+    /// Tests that a dependency repeated elsewhere in the tree still has its
+    /// path resolved, so JSON consumers can tell "duplicate but found" apart
+    /// from "genuinely missing" instead of seeing `resolved_path: null` for both
+    #[test]
+    fn test_build_dependency_tree_resolves_path_for_repeats() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("a.dll"), b"").unwrap();
+
+        let deps = vec!["a.dll".to_string(), "a.dll".to_string()];
+        let dirs = vec![temp_dir.path().to_path_buf()];
+        let tree = build_dependency_tree(ObjectFormat::Pe, &deps, &dirs, true);
+
+        assert!(!tree[0].already_shown);
+        assert!(tree[0].resolved_path.is_some());
+        assert!(tree[1].already_shown);
+        assert_eq!(tree[1].resolved_path, tree[0].resolved_path);
+    }
+
+    /// Tests that the analyzed file's own directory is searched first, ahead
+    /// of the current working directory
+    #[test]
+    fn test_default_search_paths_checks_file_dir_first() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let paths = default_search_paths(temp_file.path());
+
+        assert_eq!(paths.first().map(PathBuf::as_path), temp_file.path().parent());
+        assert!(paths.contains(&std::env::current_dir().unwrap()));
+    }
+
+    /// Tests that bare library names are expanded per-format, while names
+    /// that already carry a format-appropriate extension are left alone
+    #[test]
+    fn test_library_candidates_per_format() {
+        assert_eq!(ObjectFormat::Pe.library_candidates("foo.dll"), vec!["foo.dll"]);
+
+        assert_eq!(
+            ObjectFormat::Elf.library_candidates("foo"),
+            vec!["libfoo.so", "foo.so", "libfoo.a"]
+        );
+        assert_eq!(ObjectFormat::Elf.library_candidates("libfoo.so.6"), vec!["libfoo.so.6"]);
+
+        assert_eq!(
+            ObjectFormat::MachO.library_candidates("foo"),
+            vec!["libfoo.dylib", "foo.dylib"]
+        );
+        assert_eq!(ObjectFormat::MachO.library_candidates("libfoo.dylib"), vec!["libfoo.dylib"]);
+    }
+
+    /// Tests the JSON shape a `FileReport` serializes to: present fields keep
+    /// their names, and the `already_shown`/`children` defaults are omitted
+    #[test]
+    fn test_file_report_json_shape() {
+        let report = FileReport {
+            path: PathBuf::from("test.exe"),
+            is_dynamic: true,
+            dependencies: vec![DependencyRecord {
+                name: "foo.dll".to_string(),
+                resolved_path: Some(PathBuf::from("/lib/foo.dll")),
+                searched_dirs: vec![PathBuf::from("/lib")],
+                already_shown: false,
+                children: Vec::new(),
+            }],
+        };
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["is_dynamic"], true);
+        assert_eq!(json["dependencies"][0]["name"], "foo.dll");
+        assert_eq!(json["dependencies"][0]["resolved_path"], "/lib/foo.dll");
+        assert!(json["dependencies"][0].get("already_shown").is_none());
+        assert!(json["dependencies"][0].get("children").is_none());
+    }
 }